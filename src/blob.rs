@@ -0,0 +1,253 @@
+//! The [`AsyncBlob`] handle returned by [`crate::Connection::open_blob()`].
+
+use crate::{ Connection, Error };
+use asyncified::{ Asyncified, AsyncifiedBuilder };
+use std::future::Future;
+use std::io::{ Read, Seek, SeekFrom, Write };
+use std::pin::Pin;
+use std::task::{ Context, Poll };
+use tokio::io::{ AsyncRead, AsyncSeek, AsyncWrite, ReadBuf };
+
+// The state owned by an open blob's dedicated worker thread: the connection
+// the blob was opened against, and the blob itself.
+struct BlobState {
+    // Safety: `blob` borrows from `conn` below, which is boxed so its address
+    // stays stable for as long as `blob` is `Some`. The borrow is transmuted
+    // to `'static` so the two can live side by side in one struct; `blob` is
+    // always dropped before `conn` is read or moved out again, so the real
+    // borrow never outlives the data it points to. `blob` is declared before
+    // `conn` so that if `BlobState` is ever dropped with both fields still
+    // populated, field drop order tears down the borrow before the box it
+    // points into.
+    blob: Option<rusqlite::blob::Blob<'static>>,
+    conn: Option<Box<rusqlite::Connection>>,
+}
+
+// What a single round trip to the worker thread produced.
+enum Outcome {
+    Read(Vec<u8>),
+    Write(usize),
+    Seek(u64),
+}
+
+type PendingOp = Pin<Box<dyn Future<Output = std::io::Result<Outcome>> + Send>>;
+
+/// An open SQLite [incremental BLOB I/O](https://www.sqlite.org/c3ref/blob_open.html)
+/// handle, obtained via [`Connection::open_blob()`].
+///
+/// Implements [`tokio::io::AsyncRead`], [`tokio::io::AsyncWrite`] and
+/// [`tokio::io::AsyncSeek`] by keeping the underlying `rusqlite::Blob` pinned on
+/// its own worker thread, and shuttling fixed-size chunks of bytes (or seek
+/// requests) across to it: a read sends a length and gets bytes back, a write
+/// sends bytes and gets back the count written. This lets callers stream large
+/// blob columns to/from the network without loading them into memory whole, or
+/// blocking the async runtime.
+pub struct AsyncBlob {
+    parent: Connection,
+    conn: Asyncified<BlobState>,
+    pending: Option<PendingOp>,
+}
+
+impl AsyncBlob {
+    pub(crate) async fn open(
+        parent: Connection,
+        db: String,
+        table: String,
+        column: String,
+        row_id: i64,
+        read_only: bool,
+    ) -> Result<AsyncBlob, Error> {
+        let taken = parent.conn.call(|conn| conn.take()).await;
+        let conn = match taken {
+            Some(conn) => Box::new(conn),
+            None => return Err(Error::AlreadyClosed),
+        };
+
+        let worker = AsyncifiedBuilder::new()
+            .build(move || -> Result<BlobState, rusqlite::Error> {
+                let mut conn = conn;
+                let db_name = database_name(&db);
+                // Safety: see the comment on `BlobState::blob` above.
+                let blob = unsafe {
+                    std::mem::transmute::<rusqlite::blob::Blob<'_>, rusqlite::blob::Blob<'static>>(
+                        conn.blob_open(db_name, &table, &column, row_id, read_only)?
+                    )
+                };
+                Ok(BlobState { conn: Some(conn), blob: Some(blob) })
+            })
+            .await
+            .map_err(Error::Rusqlite)?;
+
+        Ok(AsyncBlob { parent, conn: worker, pending: None })
+    }
+
+    // Drive `self.pending` (creating it with `make` if there isn't one yet) to
+    // completion, translating its `Outcome` with `on_ready`.
+    fn poll_op<T>(
+        &mut self,
+        cx: &mut Context<'_>,
+        make: impl FnOnce() -> PendingOp,
+        on_ready: impl FnOnce(Outcome) -> std::io::Result<T>,
+    ) -> Poll<std::io::Result<T>> {
+        if self.pending.is_none() {
+            self.pending = Some(make());
+        }
+
+        let poll = self.pending.as_mut().unwrap().as_mut().poll(cx);
+        match poll {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                self.pending = None;
+                Poll::Ready(result.and_then(on_ready))
+            }
+        }
+    }
+}
+
+// "main" and "temp" are the usual special database names; anything else is
+// treated as the name of an attached database.
+fn database_name(name: &str) -> rusqlite::DatabaseName<'_> {
+    match name {
+        "main" => rusqlite::DatabaseName::Main,
+        "temp" => rusqlite::DatabaseName::Temp,
+        other => rusqlite::DatabaseName::Attached(other),
+    }
+}
+
+fn closed_err() -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::NotConnected, "the blob has already been closed")
+}
+
+// Returned when a poll method is driven to completion by an operation of a
+// different kind than the one it started - e.g. `poll_write` called while a
+// seek from `start_seek` is still in flight. `expected` names the operation
+// the caller was actually waiting on.
+fn wrong_op_err(expected: &str) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!("expected {expected} to complete, but a different operation is already in progress"),
+    )
+}
+
+impl AsyncRead for AsyncBlob {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        let len = buf.remaining();
+        let conn = self.conn.clone();
+        let poll = self.poll_op(
+            cx,
+            move || Box::pin(async move {
+                conn.call(move |state| {
+                    let blob = state.blob.as_mut().ok_or_else(closed_err)?;
+                    let mut chunk = vec![0u8; len];
+                    let n = blob.read(&mut chunk)?;
+                    chunk.truncate(n);
+                    Ok(Outcome::Read(chunk))
+                }).await
+            }),
+            |outcome| match outcome {
+                Outcome::Read(data) => Ok(data),
+                _ => Err(wrong_op_err("a read")),
+            },
+        );
+
+        match poll {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Ok(data)) => {
+                buf.put_slice(&data);
+                Poll::Ready(Ok(()))
+            }
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+impl AsyncWrite for AsyncBlob {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        let data = buf.to_vec();
+        let conn = self.conn.clone();
+        self.poll_op(
+            cx,
+            move || Box::pin(async move {
+                conn.call(move |state| {
+                    let blob = state.blob.as_mut().ok_or_else(closed_err)?;
+                    let n = blob.write(&data)?;
+                    Ok(Outcome::Write(n))
+                }).await
+            }),
+            |outcome| match outcome {
+                Outcome::Write(n) => Ok(n),
+                _ => Err(wrong_op_err("a write")),
+            },
+        )
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        // Every write already goes straight to the blob; there's nothing buffered to flush.
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncSeek for AsyncBlob {
+    fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> std::io::Result<()> {
+        if self.pending.is_some() {
+            return Err(std::io::Error::new(std::io::ErrorKind::Other, "another operation is already in progress"));
+        }
+        let conn = self.conn.clone();
+        self.pending = Some(Box::pin(async move {
+            conn.call(move |state| {
+                let blob = state.blob.as_mut().ok_or_else(closed_err)?;
+                let pos = blob.seek(position)?;
+                Ok(Outcome::Seek(pos))
+            }).await
+        }));
+        Ok(())
+    }
+
+    fn poll_complete(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<u64>> {
+        // Tokio's `seek()` calls this before `start_seek` to make sure no seek
+        // is in flight; with nothing pending, report the current position.
+        if self.pending.is_none() {
+            self.as_mut().start_seek(SeekFrom::Current(0))?;
+        }
+        let pending = self.pending.as_mut().unwrap();
+
+        match pending.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                self.pending = None;
+                Poll::Ready(result.and_then(|outcome| match outcome {
+                    Outcome::Seek(pos) => Ok(pos),
+                    _ => Err(wrong_op_err("a seek")),
+                }))
+            }
+        }
+    }
+}
+
+impl Drop for AsyncBlob {
+    fn drop(&mut self) {
+        // Best-effort: the connection is handed back to `parent`, but there's
+        // no running Tokio runtime to spawn the hand-back onto (e.g. during
+        // runtime shutdown) means it simply doesn't happen. `BlobState`'s own
+        // field order still guarantees the blob drops before the connection
+        // it borrows from, so this can't leave a dangling borrow even when
+        // the task never runs.
+        let Ok(handle) = tokio::runtime::Handle::try_current() else { return };
+        let conn = self.conn.clone();
+        let parent = self.parent.clone();
+        handle.spawn(async move {
+            let inner = conn.call(|state| {
+                state.blob.take();
+                state.conn.take()
+            }).await;
+
+            if let Some(inner) = inner {
+                parent.conn.call(move |slot| { *slot = Some(*inner); }).await;
+            }
+        });
+    }
+}
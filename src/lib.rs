@@ -48,12 +48,63 @@
 
 use asyncified::{ Asyncified, AsyncifiedBuilder };
 use std::path::Path;
+use std::time::Duration;
+
+mod stream;
+pub use stream::QueryStream;
+
+mod transaction;
+pub use transaction::Transaction;
+
+mod pool;
+pub use pool::{ Pool, PoolBuilder };
+
+mod blob;
+pub use blob::AsyncBlob;
 
 // re-export rusqlite types.
 pub use rusqlite;
 
+// The default size of the channel used to queue work for the connection's
+// worker thread, and (via `Connection::query_stream()`) to buffer rows
+// streamed back off of it.
+const DEFAULT_CHANNEL_SIZE: usize = 16;
+
+// Write extension bytes out to a fresh file in the OS temp dir so that
+// `rusqlite::Connection::load_extension` (which only accepts a path) can load
+// them, returning the path it was written to.
+fn write_temp_extension_file(bytes: &[u8]) -> rusqlite::Result<std::path::PathBuf> {
+    use std::io::Write;
+    use std::sync::atomic::{ AtomicUsize, Ordering };
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let path = std::env::temp_dir().join(format!("async-rusqlite-ext-{}-{n}", std::process::id()));
+
+    let to_err = |e: std::io::Error| {
+        rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error {
+                code: rusqlite::ffi::ErrorCode::CannotOpen,
+                extended_code: rusqlite::ffi::SQLITE_CANTOPEN,
+            },
+            Some(e.to_string()),
+        )
+    };
+
+    let mut file = std::fs::File::create(&path).map_err(to_err)?;
+    file.write_all(bytes).map_err(to_err)?;
+    Ok(path)
+}
+
+// A step run against the freshly opened `rusqlite::Connection`, on the worker
+// thread, before the connection is handed over for regular `call`s. Used to
+// queue up loadable extensions and user-defined functions at build time.
+type InitStep = Box<dyn FnOnce(&rusqlite::Connection) -> rusqlite::Result<()> + Send>;
+
 pub struct ConnectionBuilder {
-    asyncified_builder: AsyncifiedBuilder<Option<rusqlite::Connection>>
+    asyncified_builder: AsyncifiedBuilder<Option<rusqlite::Connection>>,
+    channel_size: usize,
+    init_steps: Vec<InitStep>,
 }
 
 impl std::default::Default for ConnectionBuilder {
@@ -66,7 +117,9 @@ impl ConnectionBuilder {
     /// Configure and build a new [`Connection`].
     pub fn new() -> Self {
         Self {
-            asyncified_builder: AsyncifiedBuilder::new()
+            asyncified_builder: AsyncifiedBuilder::new(),
+            channel_size: DEFAULT_CHANNEL_SIZE,
+            init_steps: Vec::new(),
         }
     }
 
@@ -78,8 +131,11 @@ impl ConnectionBuilder {
 
     /// Configure how many functions can be queued to run on our connection
     /// before `conn.call(..).await` will wait and backpressure will kick in.
+    /// This also sizes the channel that [`Connection::query_stream()`] uses
+    /// to buffer rows between the worker thread and the consumer.
     pub fn channel_size(mut self, size: usize) -> Self {
         self.asyncified_builder = self.asyncified_builder.channel_size(size);
+        self.channel_size = size;
         self
     }
 
@@ -91,6 +147,93 @@ impl ConnectionBuilder {
         self
     }
 
+    /// Queue a loadable SQLite extension (for example `crsqlite`) to be loaded from
+    /// `path` on the worker thread once the connection has opened, and before the
+    /// first [`Connection::call()`] runs. Extensions are loaded in the order they
+    /// were added, so you can layer several on top of each other.
+    ///
+    /// Note that loading an extension runs arbitrary native code inside the
+    /// SQLite process, so only point this at extensions you trust.
+    pub fn load_extension(mut self, path: impl Into<std::path::PathBuf>, entry_point: Option<impl Into<String>>) -> Self {
+        let path = path.into();
+        let entry_point = entry_point.map(Into::into);
+        self.init_steps.push(Box::new(move |conn| {
+            unsafe {
+                conn.load_extension_enable()?;
+                let result = conn.load_extension(&path, entry_point.as_deref());
+                conn.load_extension_disable()?;
+                result
+            }
+        }));
+        self
+    }
+
+    /// As [`ConnectionBuilder::load_extension()`], but the extension's shared library
+    /// bytes are supplied directly; they're written to a temporary file before being
+    /// loaded.
+    ///
+    /// Note that loading an extension runs arbitrary native code inside the
+    /// SQLite process, so only point this at extension bytes you trust.
+    pub fn load_extension_bytes(mut self, bytes: impl Into<Vec<u8>>, entry_point: Option<impl Into<String>>) -> Self {
+        let bytes = bytes.into();
+        let entry_point = entry_point.map(Into::into);
+        self.init_steps.push(Box::new(move |conn| {
+            let path = write_temp_extension_file(&bytes)?;
+            let result = unsafe {
+                conn.load_extension_enable()?;
+                let result = conn.load_extension(&path, entry_point.as_deref());
+                conn.load_extension_disable()?;
+                result
+            };
+            let _ = std::fs::remove_file(&path);
+            result
+        }));
+        self
+    }
+
+    /// Register a user-defined scalar SQL function, available to every subsequent
+    /// [`Connection::call()`]. Since the function runs on the connection's own
+    /// worker thread, this avoids having to re-register it inside every closure
+    /// that needs it. See [`rusqlite::Connection::create_scalar_function()`] for
+    /// the meaning of `n_args` and `flags`.
+    pub fn scalar_function<F, T>(mut self, name: &str, n_args: i32, flags: rusqlite::functions::FunctionFlags, f: F) -> Self
+    where
+        F: FnMut(&rusqlite::functions::Context<'_>) -> rusqlite::Result<T> + Send + 'static,
+        T: rusqlite::types::ToSql,
+    {
+        let name = name.to_owned();
+        self.init_steps.push(Box::new(move |conn| {
+            conn.create_scalar_function(&name, n_args, flags, f)
+        }));
+        self
+    }
+
+    /// Register a user-defined aggregate SQL function, available to every subsequent
+    /// [`Connection::call()`]. As with [`ConnectionBuilder::scalar_function()`], this
+    /// runs on the connection's own worker thread. See
+    /// [`rusqlite::Connection::create_aggregate_function()`] for the meaning of
+    /// `n_args` and `flags`.
+    pub fn aggregate_function<A, S, T>(mut self, name: &str, n_args: i32, flags: rusqlite::functions::FunctionFlags, agg: A) -> Self
+    where
+        A: rusqlite::functions::Aggregate<S, T> + Send + 'static,
+        T: rusqlite::types::ToSql,
+    {
+        let name = name.to_owned();
+        self.init_steps.push(Box::new(move |conn| {
+            conn.create_aggregate_function(&name, n_args, flags, agg)
+        }));
+        self
+    }
+
+    // Run every queued init step against the newly opened connection, in order,
+    // and hand it back ready for `Connection::call()`.
+    fn finish_open(init_steps: Vec<InitStep>, conn: rusqlite::Connection) -> rusqlite::Result<Option<rusqlite::Connection>> {
+        for step in init_steps {
+            step(&conn)?;
+        }
+        Ok(Some(conn))
+    }
+
     /// Open a new connection to an SQLite database. If a database does not exist at the
     /// path, one is created.
     ///
@@ -100,10 +243,12 @@ impl ConnectionBuilder {
     /// or if the underlying SQLite open call fails.
     pub async fn open<P: AsRef<Path>>(self, path: P) -> Result<Connection,rusqlite::Error> {
         let path = path.as_ref().to_owned();
+        let channel_size = self.channel_size;
+        let init_steps = self.init_steps;
         let conn = self.asyncified_builder
-            .build(move || rusqlite::Connection::open(path).map(Some))
+            .build(move || Self::finish_open(init_steps, rusqlite::Connection::open(path)?))
             .await?;
-        Ok(Connection { conn })
+        Ok(Connection { conn, channel_size })
     }
 
     /// Open a new connection to an in-memory SQLite database.
@@ -112,10 +257,12 @@ impl ConnectionBuilder {
     ///
     /// Will return `Err` if the underlying SQLite open call fails.
     pub async fn open_in_memory(self) -> Result<Connection,rusqlite::Error> {
+        let channel_size = self.channel_size;
+        let init_steps = self.init_steps;
         let conn = self.asyncified_builder
-            .build(|| rusqlite::Connection::open_in_memory().map(Some))
+            .build(move || Self::finish_open(init_steps, rusqlite::Connection::open_in_memory()?))
             .await?;
-        Ok(Connection { conn })
+        Ok(Connection { conn, channel_size })
     }
 
     /// Open a new connection to a SQLite database.
@@ -129,11 +276,13 @@ impl ConnectionBuilder {
     /// string or if the underlying SQLite open call fails.
     pub async fn open_with_flags<P: AsRef<Path>>(self, path: P, flags: rusqlite::OpenFlags) -> Result<Connection,rusqlite::Error> {
         let path = path.as_ref().to_owned();
+        let channel_size = self.channel_size;
+        let init_steps = self.init_steps;
         let conn = self
             .asyncified_builder
-            .build(move || rusqlite::Connection::open_with_flags(path, flags).map(Some))
+            .build(move || Self::finish_open(init_steps, rusqlite::Connection::open_with_flags(path, flags)?))
             .await?;
-        Ok(Connection { conn })
+        Ok(Connection { conn, channel_size })
     }
 
     /// Open a new connection to a SQLite database using the specific flags and
@@ -154,10 +303,12 @@ impl ConnectionBuilder {
     ) -> Result<Connection,rusqlite::Error> {
         let path = path.as_ref().to_owned();
         let vfs = vfs.to_owned();
+        let channel_size = self.channel_size;
+        let init_steps = self.init_steps;
         let conn = self.asyncified_builder
-            .build(move || rusqlite::Connection::open_with_flags_and_vfs(path, flags, &vfs).map(Some))
+            .build(move || Self::finish_open(init_steps, rusqlite::Connection::open_with_flags_and_vfs(path, flags, &vfs)?))
             .await?;
-        Ok(Connection { conn })
+        Ok(Connection { conn, channel_size })
     }
 
     /// Open a new connection to an in-memory SQLite database.
@@ -191,7 +342,9 @@ impl ConnectionBuilder {
 #[derive(Debug, Clone)]
 pub struct Connection {
     // None if connection is closed, else Some(connection).
-    conn: Asyncified<Option<rusqlite::Connection>>
+    pub(crate) conn: Asyncified<Option<rusqlite::Connection>>,
+    // Used to size the channel that `query_stream()` buffers rows into.
+    channel_size: usize,
 }
 
 impl Connection {
@@ -270,6 +423,191 @@ impl Connection {
             }
         }).await
     }
+
+    /// Start a transaction that can span multiple `.await` points, returning a
+    /// [`Transaction`] guard.
+    ///
+    /// Unlike [`Connection::call()`], where a `rusqlite::Transaction` could only ever
+    /// live for the duration of a single closure, the guard moves the live connection
+    /// onto a dedicated worker thread for the life of the transaction and routes
+    /// [`Transaction::call()`] closures to it, so you can read a row, make some async
+    /// decision elsewhere, and write back, all within one transaction. Dropping the
+    /// guard without calling [`Transaction::commit()`] or [`Transaction::rollback()`]
+    /// rolls the transaction back, so an abandoned guard never leaves one dangling.
+    ///
+    /// While the returned guard is live, the connection it was started from is
+    /// moved onto the guard's own worker thread, so calling [`Connection::call()`]
+    /// on this connection (or any of its clones) returns [`Error::AlreadyClosed`]
+    /// until the guard is committed, rolled back, or dropped - even though the
+    /// connection hasn't actually been closed.
+    ///
+    /// # Failure
+    ///
+    /// Will return `Err` if the connection is closed, or if starting the transaction
+    /// fails.
+    pub async fn transaction(&self) -> Result<Transaction, Error> {
+        Transaction::new(self.clone()).await
+    }
+
+    /// Open a handle to an SQLite [incremental BLOB](https://www.sqlite.org/c3ref/blob_open.html)
+    /// for streaming I/O, returning an [`AsyncBlob`] that implements
+    /// [`tokio::io::AsyncRead`], [`tokio::io::AsyncWrite`] and [`tokio::io::AsyncSeek`].
+    ///
+    /// `db` is the name of the database the blob lives in (`"main"` for the
+    /// default database), `table` and `column` name the column to read/write,
+    /// and `row_id` picks the row. Unlike [`Connection::call()`], this moves the
+    /// connection onto its own worker thread for the lifetime of the returned
+    /// handle, so the blob can be streamed across as many `.await` points as
+    /// needed; the connection is handed back once the handle is dropped.
+    ///
+    /// While the returned handle is live, the connection it was opened from is
+    /// moved onto the handle's own worker thread, so calling [`Connection::call()`]
+    /// on this connection (or any of its clones) returns [`Error::AlreadyClosed`]
+    /// until the handle is dropped - even though the connection hasn't actually
+    /// been closed.
+    ///
+    /// # Failure
+    ///
+    /// Will return `Err` if the connection is closed, or if the underlying
+    /// SQLite call to open the blob fails.
+    pub async fn open_blob(
+        &self,
+        db: impl Into<String>,
+        table: impl Into<String>,
+        column: impl Into<String>,
+        row_id: i64,
+        read_only: bool,
+    ) -> Result<AsyncBlob, Error> {
+        AsyncBlob::open(self.clone(), db.into(), table.into(), column.into(), row_id, read_only).await
+    }
+
+    /// Run a query against the connection and stream the results back one row at a
+    /// time, instead of collecting them all into a `Vec` first.
+    ///
+    /// `sql` is prepared and `map_row` is applied via `query_map` on the worker
+    /// thread; each mapped (and therefore owned, `Send`) row is pushed into a
+    /// channel sized by [`ConnectionBuilder::channel_size()`]. The worker thread
+    /// blocks once that channel is full, so a slow consumer naturally
+    /// backpressures the query instead of the whole result set being buffered
+    /// in memory up front.
+    pub fn query_stream<T, P>(
+        &self,
+        sql: impl Into<String>,
+        params: P,
+        mut map_row: impl FnMut(&rusqlite::Row<'_>) -> rusqlite::Result<T> + Send + 'static,
+    ) -> QueryStream<T>
+    where
+        T: Send + 'static,
+        P: rusqlite::Params + Send + 'static,
+    {
+        let sql = sql.into();
+        let (tx, rx) = tokio::sync::mpsc::channel(self.channel_size);
+        let conn = self.conn.clone();
+
+        tokio::spawn(async move {
+            conn.call(move |conn| {
+                let conn = match conn {
+                    Some(conn) => conn,
+                    None => {
+                        let _ = tx.blocking_send(Err(AlreadyClosed.into()));
+                        return;
+                    }
+                };
+
+                let result = (|| -> Result<(), Error> {
+                    let mut stmt = conn.prepare(&sql)?;
+                    let mut rows = stmt.query_map(params, |row| map_row(row))?;
+                    for row in &mut rows {
+                        if tx.blocking_send(row.map_err(Error::from)).is_err() {
+                            // Consumer has dropped the stream; stop producing rows.
+                            return Ok(());
+                        }
+                    }
+                    Ok(())
+                })();
+
+                if let Err(e) = result {
+                    let _ = tx.blocking_send(Err(e));
+                }
+            }).await
+        });
+
+        QueryStream::new(rx)
+    }
+
+    /// Back this database up to `dest_path`, using SQLite's
+    /// [online backup API](https://www.sqlite.org/backup.html).
+    ///
+    /// The copy runs in fixed-size batches of `pages_per_step` pages, sleeping for
+    /// `pause` between each step and calling `progress` with the remaining/total
+    /// page counts after every step, until the whole database has been copied.
+    /// Because the whole loop runs on this connection's worker thread, it can hold
+    /// the backup handle against the live connection without any `Send` or borrow
+    /// issues.
+    ///
+    /// # Failure
+    ///
+    /// Will return `Err` if the connection is closed, the destination cannot be
+    /// opened, or the underlying SQLite backup calls fail.
+    pub async fn backup_to<P: AsRef<Path>>(
+        &self,
+        dest_path: P,
+        pages_per_step: i32,
+        pause: Duration,
+        mut progress: impl FnMut(rusqlite::backup::Progress) + Send + 'static,
+    ) -> Result<(), Error> {
+        let dest_path = dest_path.as_ref().to_owned();
+        self.call(move |conn| {
+            let mut dest = rusqlite::Connection::open(dest_path)?;
+            let backup = rusqlite::backup::Backup::new(conn, &mut dest)?;
+            run_backup_to_completion(&backup, pages_per_step, pause, &mut progress)?;
+            Ok(())
+        }).await
+    }
+
+    /// Restore this database from a backup at `src_path`, the reverse of
+    /// [`Connection::backup_to()`]. See that method for the meaning of
+    /// `pages_per_step`, `pause` and `progress`.
+    ///
+    /// # Failure
+    ///
+    /// Will return `Err` if the connection is closed, the source cannot be
+    /// opened, or the underlying SQLite backup calls fail.
+    pub async fn restore_from<P: AsRef<Path>>(
+        &self,
+        src_path: P,
+        pages_per_step: i32,
+        pause: Duration,
+        mut progress: impl FnMut(rusqlite::backup::Progress) + Send + 'static,
+    ) -> Result<(), Error> {
+        let src_path = src_path.as_ref().to_owned();
+        self.call(move |conn| {
+            let src = rusqlite::Connection::open(src_path)?;
+            let backup = rusqlite::backup::Backup::new(&src, conn)?;
+            run_backup_to_completion(&backup, pages_per_step, pause, &mut progress)?;
+            Ok(())
+        }).await
+    }
+}
+
+// `Backup::run_to_completion` only accepts a bare `fn(Progress)` for its
+// progress callback, so it can't forward a capturing `FnMut` like the one
+// `backup_to`/`restore_from` take. Step the backup manually instead, calling
+// `progress` ourselves after every step.
+fn run_backup_to_completion(
+    backup: &rusqlite::backup::Backup<'_, '_>,
+    pages_per_step: i32,
+    pause: Duration,
+    progress: &mut dyn FnMut(rusqlite::backup::Progress),
+) -> rusqlite::Result<()> {
+    loop {
+        let result = backup.step(pages_per_step)?;
+        progress(backup.progress());
+        if result == rusqlite::backup::StepResult::Done {
+            return Ok(());
+        }
+        std::thread::sleep(pause);
+    }
 }
 
 /// If the connection is already closed, this will be returned
@@ -299,6 +637,14 @@ pub enum Error {
     AlreadyClosed,
     /// A `rusqlite` error occured trying to close the connection.
     Rusqlite(rusqlite::Error),
+    /// [`Pool::builder()`](crate::PoolBuilder) was asked to open an in-memory
+    /// database without shared-cache mode, so the reader connections wouldn't see
+    /// the same database as the writer.
+    InMemoryPoolNotShared,
+    /// [`Pool::builder()`](crate::PoolBuilder) was configured with zero reader
+    /// connections, which would leave [`Pool::read()`](crate::Pool::read) with
+    /// nowhere to dispatch to.
+    PoolNeedsAtLeastOneReader,
 }
 
 impl std::fmt::Display for Error {
@@ -306,6 +652,8 @@ impl std::fmt::Display for Error {
         match self {
             Error::AlreadyClosed => write!(f, "The connection has already been closed"),
             Error::Rusqlite(e) => write!(f, "Rusqlite error: {e}"),
+            Error::InMemoryPoolNotShared => write!(f, "An in-memory database path was given to a Pool without shared-cache mode enabled"),
+            Error::PoolNeedsAtLeastOneReader => write!(f, "A Pool must be built with at least one reader connection"),
         }
     }
 }
@@ -315,6 +663,8 @@ impl std::error::Error for Error {
         match self {
             Error::AlreadyClosed => None,
             Error::Rusqlite(e) => Some(e),
+            Error::InMemoryPoolNotShared => None,
+            Error::PoolNeedsAtLeastOneReader => None,
         }
     }
 }
@@ -439,4 +789,192 @@ mod test {
         let db = rx.await.unwrap();
         assert!(db.is_some());
     }
+
+    #[tokio::test]
+    async fn backup_and_restore_roundtrip() {
+        let dir = std::env::temp_dir();
+        let backup_path = dir.join("async_rusqlite_test_backup.sqlite3");
+        let _ = std::fs::remove_file(&backup_path);
+
+        let conn = Connection::open_in_memory().await.unwrap();
+        conn.call(|conn| {
+            conn.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, n INTEGER NOT NULL)", ())
+        }).await.unwrap();
+        conn.call(|conn| conn.execute("INSERT INTO t (n) VALUES (1), (2), (3)", ())).await.unwrap();
+
+        conn.backup_to(&backup_path, 5, Duration::from_millis(0), |_progress| {}).await.unwrap();
+
+        let restored = Connection::open_in_memory().await.unwrap();
+        restored.restore_from(&backup_path, 5, Duration::from_millis(0), |_progress| {}).await.unwrap();
+
+        let count: usize = restored.call(|conn| {
+            conn.query_row("SELECT count(n) FROM t", (), |r| r.get(0))
+        }).await.unwrap();
+
+        assert_eq!(count, 3);
+        let _ = std::fs::remove_file(&backup_path);
+    }
+
+    #[tokio::test]
+    async fn query_stream_yields_every_row() {
+        use futures_util::StreamExt;
+
+        let conn = Connection::open_in_memory().await.unwrap();
+        conn.call(|conn| {
+            conn.execute(
+                "CREATE TABLE numbers (
+                    id   INTEGER PRIMARY KEY,
+                    num  INTEGER NOT NULL
+                )",
+                (),
+            )
+        }).await.unwrap();
+
+        for n in 0..100 {
+            conn.call(move |conn| conn.execute("INSERT INTO numbers (num) VALUES (?1)", (n,))).await.unwrap();
+        }
+
+        let mut stream = conn.query_stream(
+            "SELECT num FROM numbers ORDER BY num",
+            (),
+            |row| row.get::<_, i64>(0),
+        );
+
+        let mut seen = vec![];
+        while let Some(n) = stream.next().await {
+            seen.push(n.unwrap());
+        }
+
+        assert_eq!(seen, (0..100).collect::<Vec<i64>>());
+    }
+
+    #[tokio::test]
+    async fn transaction_commit_keeps_writes() {
+        let conn = Connection::open_in_memory().await.unwrap();
+        conn.call(|conn| conn.execute("CREATE TABLE t (n INTEGER NOT NULL)", ())).await.unwrap();
+
+        let tx = conn.transaction().await.unwrap();
+        tx.call(|tx| tx.execute("INSERT INTO t (n) VALUES (1)", ())).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let count: usize = conn.call(|conn| conn.query_row("SELECT count(n) FROM t", (), |r| r.get(0))).await.unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn transaction_rollback_discards_writes() {
+        let conn = Connection::open_in_memory().await.unwrap();
+        conn.call(|conn| conn.execute("CREATE TABLE t (n INTEGER NOT NULL)", ())).await.unwrap();
+
+        let tx = conn.transaction().await.unwrap();
+        tx.call(|tx| tx.execute("INSERT INTO t (n) VALUES (1)", ())).await.unwrap();
+        tx.rollback().await.unwrap();
+
+        let count: usize = conn.call(|conn| conn.query_row("SELECT count(n) FROM t", (), |r| r.get(0))).await.unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn abandoned_transaction_rolls_back() {
+        let conn = Connection::open_in_memory().await.unwrap();
+        conn.call(|conn| conn.execute("CREATE TABLE t (n INTEGER NOT NULL)", ())).await.unwrap();
+
+        {
+            let tx = conn.transaction().await.unwrap();
+            tx.call(|tx| tx.execute("INSERT INTO t (n) VALUES (1)", ())).await.unwrap();
+            // `tx` is dropped here without commit/rollback.
+        }
+
+        // Give the drop's background rollback a moment to run before we
+        // try to use the connection again.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let count: usize = conn.call(|conn| conn.query_row("SELECT count(n) FROM t", (), |r| r.get(0))).await.unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn load_extension_failure_surfaces_as_open_error() {
+        // There's no real extension to load in this test, but we can check that a
+        // bad path is surfaced as an open error rather than silently ignored.
+        let err = Connection::builder()
+            .load_extension("/no/such/extension/here", None::<String>)
+            .open_in_memory()
+            .await
+            .expect_err("loading a missing extension should fail to open");
+
+        assert!(matches!(err, rusqlite::Error::SqliteFailure(..)));
+    }
+
+    #[tokio::test]
+    async fn registered_scalar_function_is_usable_in_queries() {
+        let conn = Connection::builder()
+            .scalar_function("double_it", 1, rusqlite::functions::FunctionFlags::SQLITE_UTF8, |ctx| {
+                let n: i64 = ctx.get(0)?;
+                Ok(n * 2)
+            })
+            .open_in_memory()
+            .await
+            .unwrap();
+
+        let doubled: i64 = conn.call(|conn| conn.query_row("SELECT double_it(21)", (), |r| r.get(0))).await.unwrap();
+        assert_eq!(doubled, 42);
+    }
+
+    #[tokio::test]
+    async fn pool_rejects_unshared_memory_path() {
+        let err = Pool::builder().open(":memory:").await.expect_err("should reject plain :memory:");
+        assert_eq!(err, Error::InMemoryPoolNotShared);
+    }
+
+    #[tokio::test]
+    async fn pool_rejects_zero_readers() {
+        let path = std::env::temp_dir().join("async_rusqlite_test_pool_zero_readers.sqlite3");
+        let _ = std::fs::remove_file(&path);
+
+        let err = Pool::builder().num_readers(0).open(&path).await.expect_err("should reject zero readers");
+        assert_eq!(err, Error::PoolNeedsAtLeastOneReader);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn pool_write_then_read() {
+        let path = std::env::temp_dir().join("async_rusqlite_test_pool.sqlite3");
+        let _ = std::fs::remove_file(&path);
+
+        let pool = Pool::builder().num_readers(2).open(&path).await.unwrap();
+
+        pool.write(|conn| conn.execute("CREATE TABLE t (n INTEGER NOT NULL)", ())).await.unwrap();
+        pool.write(|conn| conn.execute("INSERT INTO t (n) VALUES (1), (2)", ())).await.unwrap();
+
+        let count: usize = pool.read(|conn| conn.query_row("SELECT count(n) FROM t", (), |r| r.get(0))).await.unwrap();
+        assert_eq!(count, 2);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn async_blob_reads_and_writes() {
+        use tokio::io::{ AsyncReadExt, AsyncSeekExt, AsyncWriteExt };
+
+        let conn = Connection::open_in_memory().await.unwrap();
+        conn.call(|conn| conn.execute("CREATE TABLE blobs (id INTEGER PRIMARY KEY, data BLOB NOT NULL)", ())).await.unwrap();
+        conn.call(|conn| conn.execute("INSERT INTO blobs (id, data) VALUES (1, ZEROBLOB(5))", ())).await.unwrap();
+
+        let mut blob = conn.open_blob("main", "blobs", "data", 1, false).await.unwrap();
+        blob.write_all(b"hello").await.unwrap();
+        blob.seek(std::io::SeekFrom::Start(0)).await.unwrap();
+
+        let mut out = [0u8; 5];
+        blob.read_exact(&mut out).await.unwrap();
+        assert_eq!(&out, b"hello");
+
+        drop(blob);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // The connection should be usable again now that the blob has been dropped.
+        let data: Vec<u8> = conn.call(|conn| conn.query_row("SELECT data FROM blobs WHERE id = 1", (), |r| r.get(0))).await.unwrap();
+        assert_eq!(data, b"hello");
+    }
 }
\ No newline at end of file
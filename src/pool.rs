@@ -0,0 +1,130 @@
+//! A [`Pool`] of connections for concurrent reads, built via [`PoolBuilder`].
+
+use crate::{ AlreadyClosed, Connection, Error };
+use std::path::Path;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+/// Configure and build a new [`Pool`].
+pub struct PoolBuilder {
+    num_readers: usize,
+}
+
+impl std::default::Default for PoolBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoolBuilder {
+    /// Configure and build a new [`Pool`].
+    pub fn new() -> Self {
+        Self { num_readers: 4 }
+    }
+
+    /// Configure how many read-only connections the pool opens. Each gets its own
+    /// worker thread, so queries dispatched via [`Pool::read()`] can run
+    /// concurrently across them. Defaults to 4.
+    pub fn num_readers(mut self, num_readers: usize) -> Self {
+        self.num_readers = num_readers;
+        self
+    }
+
+    /// Open the pool: one read-write connection for [`Pool::write()`], and
+    /// `num_readers` read-only connections for [`Pool::read()`], each opened
+    /// against `path` and given its own worker thread, so that SQLite's
+    /// WAL-mode support for concurrent readers actually gets used.
+    ///
+    /// # Failure
+    ///
+    /// `path` must either point at a file on disk, or be an in-memory database
+    /// opened with shared-cache mode (e.g. `file::memory:?cache=shared`) -
+    /// otherwise each reader would see its own, separate in-memory database, so
+    /// this returns [`Error::InMemoryPoolNotShared`] rather than silently doing
+    /// the wrong thing. Also fails if any of the underlying connections can't be
+    /// opened.
+    pub async fn open<P: AsRef<Path>>(self, path: P) -> Result<Pool, Error> {
+        let path = path.as_ref();
+        if is_unshared_memory_path(path) {
+            return Err(Error::InMemoryPoolNotShared);
+        }
+        if self.num_readers == 0 {
+            return Err(Error::PoolNeedsAtLeastOneReader);
+        }
+
+        let writer = Connection::open_with_flags(
+            path,
+            rusqlite::OpenFlags::SQLITE_OPEN_READ_WRITE
+                | rusqlite::OpenFlags::SQLITE_OPEN_CREATE
+                | rusqlite::OpenFlags::SQLITE_OPEN_URI,
+        ).await?;
+        writer.call(|conn| conn.pragma_update(None, "journal_mode", "WAL")).await?;
+
+        let mut readers = Vec::with_capacity(self.num_readers);
+        for _ in 0..self.num_readers {
+            let reader = Connection::open_with_flags(
+                path,
+                rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_URI,
+            ).await?;
+            readers.push(reader);
+        }
+
+        Ok(Pool { writer, readers, next_reader: std::sync::Arc::new(AtomicUsize::new(0)) })
+    }
+}
+
+// `:memory:` (plain or as a `file::memory:` URI) and the implicit `""`
+// temp-database path are private to the connection that opened them unless
+// shared-cache mode is turned on, in which case the path mentions
+// `cache=shared`. Matches the constraint documented by the
+// bb8-rusqlite/r2d2-sqlite connection pool managers.
+fn is_unshared_memory_path(path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    let is_memory = path.is_empty() || path.contains(":memory:") || path.contains("mode=memory");
+    is_memory && !path.contains("cache=shared")
+}
+
+/// A pool of SQLite connections that allows concurrent reads.
+///
+/// A single worker thread serializes all queries made through a plain
+/// [`Connection`], so a slow read blocks every other caller. `Pool` instead
+/// opens several read-only connections (each with its own worker thread)
+/// alongside one writer connection, and relies on SQLite's WAL mode to let
+/// those readers run concurrently with each other and with the writer.
+#[derive(Debug, Clone)]
+pub struct Pool {
+    writer: Connection,
+    readers: Vec<Connection>,
+    next_reader: std::sync::Arc<AtomicUsize>,
+}
+
+impl Pool {
+    /// Configure and build a new pool.
+    pub fn builder() -> PoolBuilder {
+        PoolBuilder::new()
+    }
+
+    /// Run some arbitrary function against the writer connection and return the result.
+    /// See [`Connection::call()`] for the meaning of the error bound.
+    pub async fn write<R, E, F>(&self, f: F) -> Result<R, E>
+    where
+        R: Send + 'static,
+        E: Send + 'static + From<AlreadyClosed>,
+        F: Send + 'static + FnOnce(&mut rusqlite::Connection) -> Result<R, E>,
+    {
+        self.writer.call(f).await
+    }
+
+    /// Run some arbitrary function against one of the read-only connections and
+    /// return the result. Readers are chosen round-robin, so concurrent calls to
+    /// `read` spread out across the pool instead of queueing behind one thread.
+    /// See [`Connection::call()`] for the meaning of the error bound.
+    pub async fn read<R, E, F>(&self, f: F) -> Result<R, E>
+    where
+        R: Send + 'static,
+        E: Send + 'static + From<AlreadyClosed>,
+        F: Send + 'static + FnOnce(&mut rusqlite::Connection) -> Result<R, E>,
+    {
+        let i = self.next_reader.fetch_add(1, Ordering::Relaxed) % self.readers.len();
+        self.readers[i].call(f).await
+    }
+}
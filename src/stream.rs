@@ -0,0 +1,28 @@
+//! The [`QueryStream`] type returned by [`crate::Connection::query_stream()`].
+
+use crate::Error;
+use std::pin::Pin;
+use std::task::{ Context, Poll };
+
+/// A stream of mapped rows produced by [`crate::Connection::query_stream()`].
+///
+/// Implements [`futures_core::Stream`] (and so also `futures::Stream`, which
+/// re-exports the same trait), yielding one item per row as the connection's
+/// worker thread produces them.
+pub struct QueryStream<T> {
+    rx: tokio::sync::mpsc::Receiver<Result<T, Error>>,
+}
+
+impl<T> QueryStream<T> {
+    pub(crate) fn new(rx: tokio::sync::mpsc::Receiver<Result<T, Error>>) -> Self {
+        Self { rx }
+    }
+}
+
+impl<T> futures_core::Stream for QueryStream<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
@@ -0,0 +1,142 @@
+//! The [`Transaction`] guard returned by [`crate::Connection::transaction()`].
+
+use crate::{ AlreadyClosed, Connection, Error };
+use asyncified::{ Asyncified, AsyncifiedBuilder };
+
+// The state owned by a transaction's dedicated worker thread: the connection
+// the transaction was started against, and the transaction itself.
+struct TxState {
+    // Safety: `tx` borrows from `conn` below, which is boxed so its address
+    // stays stable for as long as `tx` is `Some`. The borrow is transmuted to
+    // `'static` so the two can live side by side in one struct; `tx` is
+    // always taken and ended (committed/rolled back) before `conn` is read
+    // or moved out again, so the real borrow never outlives the data it
+    // points to. `tx` is declared before `conn` so that if `TxState` is ever
+    // dropped with both fields still populated, field drop order tears down
+    // the borrow before the box it points into.
+    tx: Option<rusqlite::Transaction<'static>>,
+    conn: Option<Box<rusqlite::Connection>>,
+}
+
+/// A handle to an open transaction, obtained via [`Connection::transaction()`].
+///
+/// Use [`Transaction::call()`] to run closures against the live
+/// `rusqlite::Transaction` on its worker thread, across as many `.await` points
+/// as you like, then finish up with [`Transaction::commit()`] or
+/// [`Transaction::rollback()`].
+#[derive(Debug)]
+pub struct Transaction {
+    parent: Connection,
+    tx: Asyncified<TxState>,
+}
+
+impl Transaction {
+    pub(crate) async fn new(parent: Connection) -> Result<Transaction, Error> {
+        let taken = parent.conn.call(|conn| conn.take()).await;
+        let conn = match taken {
+            Some(conn) => Box::new(conn),
+            None => return Err(Error::AlreadyClosed),
+        };
+
+        let tx = AsyncifiedBuilder::new()
+            .build(move || -> Result<TxState, rusqlite::Error> {
+                let mut conn = conn;
+                // Safety: see the comment on `TxState::tx` above.
+                let tx = unsafe {
+                    std::mem::transmute::<rusqlite::Transaction<'_>, rusqlite::Transaction<'static>>(
+                        conn.transaction()?
+                    )
+                };
+                Ok(TxState { conn: Some(conn), tx: Some(tx) })
+            })
+            .await
+            .map_err(Error::Rusqlite)?;
+
+        Ok(Transaction { parent, tx })
+    }
+
+    /// Run some arbitrary function against the live `rusqlite::Transaction` and
+    /// return the result.
+    ///
+    /// # Failure
+    ///
+    /// Will return `Err` if the transaction has already been committed or rolled
+    /// back, or if the provided function returns an error. The error type must
+    /// impl [`From<AlreadyClosed>`] to handle this possibility being emitted.
+    pub async fn call<R, E, F>(&self, f: F) -> Result<R, E>
+    where
+        R: Send + 'static,
+        E: Send + 'static + From<AlreadyClosed>,
+        F: Send + 'static + FnOnce(&mut rusqlite::Transaction<'_>) -> Result<R, E>,
+    {
+        self.tx.call(|state| {
+            match state.tx.as_mut() {
+                Some(tx) => f(tx),
+                None => Err(AlreadyClosed.into()),
+            }
+        }).await
+    }
+
+    /// Commit the transaction.
+    ///
+    /// # Failure
+    ///
+    /// Will return `Err` if the underlying SQLite commit fails.
+    pub async fn commit(self) -> Result<(), Error> {
+        self.end(|tx| tx.commit()).await
+    }
+
+    /// Roll the transaction back.
+    ///
+    /// # Failure
+    ///
+    /// Will return `Err` if the underlying SQLite rollback fails.
+    pub async fn rollback(self) -> Result<(), Error> {
+        self.end(|tx| tx.rollback()).await
+    }
+
+    async fn end(
+        self,
+        f: impl FnOnce(rusqlite::Transaction<'_>) -> rusqlite::Result<()> + Send + 'static,
+    ) -> Result<(), Error> {
+        let (result, conn) = self.tx.call(move |state| {
+            let result = match state.tx.take() {
+                Some(tx) => f(tx).map_err(Error::from),
+                None => Err(Error::AlreadyClosed),
+            };
+            (result, state.conn.take())
+        }).await;
+
+        if let Some(conn) = conn {
+            self.parent.conn.call(move |slot| { *slot = Some(*conn); }).await;
+        }
+        result
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        // Best-effort: an abandoned transaction is rolled back and the
+        // connection handed back to `parent`, but there's nobody left to
+        // report a failure to, and no running Tokio runtime to spawn the
+        // cleanup onto (e.g. during runtime shutdown) means it simply
+        // doesn't happen. `TxState`'s own field order still guarantees that
+        // drops the transaction before the connection it borrows from, so
+        // this can't leave a dangling borrow even when the task never runs.
+        let Ok(handle) = tokio::runtime::Handle::try_current() else { return };
+        let tx = self.tx.clone();
+        let parent = self.parent.clone();
+        handle.spawn(async move {
+            let conn = tx.call(|state| {
+                if let Some(tx) = state.tx.take() {
+                    let _ = tx.rollback();
+                }
+                state.conn.take()
+            }).await;
+
+            if let Some(conn) = conn {
+                parent.conn.call(move |slot| { *slot = Some(*conn); }).await;
+            }
+        });
+    }
+}